@@ -7,19 +7,19 @@ use std::{
     process::Command,
 };
 
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 use tempfile::tempdir;
 
 use crate::roblox_install::RobloxStudio;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Dump {
     pub classes: Vec<DumpClass>,
     pub enums: Vec<DumpEnum>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DumpClass {
     pub name: String,
@@ -30,7 +30,7 @@ pub struct DumpClass {
     pub members: Vec<DumpClassMember>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "MemberType")]
 pub enum DumpClassMember {
     Property(DumpClassProperty),
@@ -49,7 +49,7 @@ pub enum DumpClassMember {
     Unknown,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DumpClassProperty {
     pub name: String,
@@ -60,14 +60,14 @@ pub struct DumpClassProperty {
     pub tags: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ValueType {
     pub name: String,
     pub category: ValueCategory,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum ValueCategory {
     /// Lua primitives like float or string
     Primitive,
@@ -82,21 +82,21 @@ pub enum ValueCategory {
     Class,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Serialization {
     pub can_save: bool,
     pub can_load: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DumpEnum {
     pub name: String,
     pub items: Vec<DumpEnumItem>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DumpEnumItem {
     pub name: String,
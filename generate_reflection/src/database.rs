@@ -1,15 +1,149 @@
 use std::{
     borrow::Cow,
     collections::HashMap,
+    fs::File,
+    io,
+    path::Path,
 };
 
+use serde_derive::{Deserialize, Serialize};
+
 use crate::{
     api_dump::Dump,
-    reflection_types::RbxClassDescriptor,
+    reflection_types::{RbxClassDescriptor, RbxEnumDescriptor},
+    roblox_install::RobloxStudio,
 };
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ReflectionDatabase {
     pub dump: Dump,
     pub studio_version: [u32; 4],
     pub classes: HashMap<Cow<'static, str>, RbxClassDescriptor>,
-}
\ No newline at end of file
+    pub enums: HashMap<Cow<'static, str>, RbxEnumDescriptor>,
+}
+
+impl ReflectionDatabase {
+    /// Builds a fresh `ReflectionDatabase` from the given API dump, as
+    /// produced by the Studio version it was read from.
+    fn from_dump(dump: Dump, studio_version: [u32; 4]) -> ReflectionDatabase {
+        let classes = dump
+            .classes
+            .iter()
+            .map(|dump_class| (
+                Cow::Owned(dump_class.name.clone()),
+                RbxClassDescriptor::from_dump_class(dump_class),
+            ))
+            .collect();
+
+        let enums = dump
+            .enums
+            .iter()
+            .map(|dump_enum| (
+                Cow::Owned(dump_enum.name.clone()),
+                RbxEnumDescriptor::from_dump_enum(dump_enum),
+            ))
+            .collect();
+
+        ReflectionDatabase {
+            dump,
+            studio_version,
+            classes,
+            enums,
+        }
+    }
+
+    /// Locates the installed Roblox Studio, invokes it to produce a fresh API
+    /// dump, and builds a `ReflectionDatabase` from the result.
+    fn generate(studio_version: [u32; 4]) -> io::Result<ReflectionDatabase> {
+        let dump = Dump::read()?;
+
+        Ok(ReflectionDatabase::from_dump(dump, studio_version))
+    }
+
+    /// Writes this database to `path` as a compact binary blob that can later
+    /// be recovered with `ReflectionDatabase::load`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let output = File::create(path)?;
+
+        bincode::serialize_into(output, self)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    }
+
+    /// Loads a `ReflectionDatabase` from the cache at `path` if it exists and
+    /// was produced by the Studio version currently installed. Otherwise,
+    /// falls back to invoking Studio to generate a fresh dump.
+    ///
+    /// If Studio isn't installed, the cache is used as-is without a version
+    /// check. This is what lets a downstream crate bundle a prebuilt database
+    /// and load it on a machine with no Studio install at all.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<ReflectionDatabase> {
+        let cached = Self::read_cache(path.as_ref());
+
+        match RobloxStudio::locate() {
+            Ok(studio_install) => {
+                let current_version = studio_install.version();
+
+                match cached {
+                    Some(cached) if cached.studio_version == current_version => Ok(cached),
+                    _ => ReflectionDatabase::generate(current_version),
+                }
+            }
+            Err(err) => cached.ok_or(err),
+        }
+    }
+
+    fn read_cache(path: &Path) -> Option<ReflectionDatabase> {
+        let file = File::open(path).ok()?;
+
+        bincode::deserialize_from(file).ok()
+    }
+
+    /// Finds the descriptor for the enum with the given name, if it's known
+    /// to this database.
+    pub fn find_enum_descriptor(&self, enum_name: &str) -> Option<&RbxEnumDescriptor> {
+        self.enums.get(enum_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn empty_database(studio_version: [u32; 4]) -> ReflectionDatabase {
+        ReflectionDatabase {
+            dump: Dump {
+                classes: Vec::new(),
+                enums: Vec::new(),
+            },
+            studio_version,
+            classes: HashMap::new(),
+            enums: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn save_and_read_cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache.bin");
+
+        let database = empty_database([1, 2, 3, 4]);
+        database.save(&path).unwrap();
+
+        let cached = ReflectionDatabase::read_cache(&path).unwrap();
+        assert_eq!(cached.studio_version, database.studio_version);
+    }
+
+    #[test]
+    fn load_falls_back_to_cache_without_studio() {
+        // This sandbox never has Studio installed, so `load` should use
+        // whatever was cached on disk rather than erroring out.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache.bin");
+
+        let database = empty_database([9, 9, 9, 9]);
+        database.save(&path).unwrap();
+
+        let loaded = ReflectionDatabase::load(&path).unwrap();
+        assert_eq!(loaded.studio_version, [9, 9, 9, 9]);
+    }
+}
@@ -0,0 +1,145 @@
+//! The descriptor types that make up a `ReflectionDatabase`. These mirror the
+//! shape of the raw API dump, but are cheaper to query and are what actually
+//! gets persisted to (and loaded from) the on-disk cache.
+
+use std::{borrow::Cow, collections::HashMap};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::api_dump::{DumpClass, DumpClassMember, DumpEnum};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RbxClassDescriptor {
+    pub name: Cow<'static, str>,
+    pub superclass: Option<Cow<'static, str>>,
+    pub tags: Vec<Cow<'static, str>>,
+    pub properties: HashMap<Cow<'static, str>, RbxPropertyDescriptor>,
+}
+
+impl RbxClassDescriptor {
+    pub(crate) fn from_dump_class(dump_class: &DumpClass) -> RbxClassDescriptor {
+        let mut properties = HashMap::new();
+
+        for member in &dump_class.members {
+            if let DumpClassMember::Property(dump_property) = member {
+                properties.insert(
+                    Cow::Owned(dump_property.name.clone()),
+                    RbxPropertyDescriptor::from_dump_property(dump_property),
+                );
+            }
+        }
+
+        RbxClassDescriptor {
+            name: Cow::Owned(dump_class.name.clone()),
+            superclass: match dump_class.superclass.as_str() {
+                "<<<ROOT>>>" => None,
+                superclass => Some(Cow::Owned(superclass.to_owned())),
+            },
+            tags: dump_class.tags.iter().cloned().map(Cow::Owned).collect(),
+            properties,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RbxPropertyDescriptor {
+    pub name: Cow<'static, str>,
+    pub tags: Vec<Cow<'static, str>>,
+
+    /// Whether Studio will write this property out when saving a place or
+    /// model file.
+    pub can_save: bool,
+
+    /// Whether Studio will read this property back in when loading a place
+    /// or model file.
+    pub can_load: bool,
+
+    pub scriptability: Scriptability,
+
+    /// Whether this property is a deprecated alias kept around for backwards
+    /// compatibility with old files and scripts.
+    pub deprecated: bool,
+}
+
+impl RbxPropertyDescriptor {
+    fn from_dump_property(dump_property: &crate::api_dump::DumpClassProperty) -> RbxPropertyDescriptor {
+        RbxPropertyDescriptor {
+            name: Cow::Owned(dump_property.name.clone()),
+            can_save: dump_property.serialization.can_save,
+            can_load: dump_property.serialization.can_load,
+            scriptability: Scriptability::from_tags(&dump_property.tags),
+            deprecated: dump_property.tags.iter().any(|tag| tag == "Deprecated"),
+            tags: dump_property.tags.iter().cloned().map(Cow::Owned).collect(),
+        }
+    }
+}
+
+/// Describes how a property can be accessed from Lua scripts, derived from
+/// the `ReadOnly`, `WriteOnly`, and `NotScriptable` tags in the API dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scriptability {
+    /// Can be both read and written to from Lua.
+    ReadWrite,
+
+    /// Can only be read from Lua; the engine computes or otherwise controls
+    /// the value.
+    Read,
+
+    /// Can only be written to from Lua, never read back.
+    Write,
+
+    /// Not accessible from Lua scripts at all.
+    None,
+}
+
+impl Scriptability {
+    fn from_tags(tags: &[String]) -> Scriptability {
+        if tags.iter().any(|tag| tag == "NotScriptable") {
+            Scriptability::None
+        } else if tags.iter().any(|tag| tag == "ReadOnly") {
+            Scriptability::Read
+        } else if tags.iter().any(|tag| tag == "WriteOnly") {
+            Scriptability::Write
+        } else {
+            Scriptability::ReadWrite
+        }
+    }
+}
+
+/// Describes a Roblox enum, such as `FormFactor` or `Genre`, keeping both the
+/// forward (name to value) and reverse (value to name) mappings so that
+/// consumers can resolve enum-typed properties in either direction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RbxEnumDescriptor {
+    pub name: Cow<'static, str>,
+    pub value_to_name: HashMap<u32, Cow<'static, str>>,
+    pub name_to_value: HashMap<Cow<'static, str>, u32>,
+}
+
+impl RbxEnumDescriptor {
+    pub(crate) fn from_dump_enum(dump_enum: &DumpEnum) -> RbxEnumDescriptor {
+        let mut value_to_name = HashMap::new();
+        let mut name_to_value = HashMap::new();
+
+        for item in &dump_enum.items {
+            value_to_name.insert(item.value, Cow::Owned(item.name.clone()));
+            name_to_value.insert(Cow::Owned(item.name.clone()), item.value);
+        }
+
+        RbxEnumDescriptor {
+            name: Cow::Owned(dump_enum.name.clone()),
+            value_to_name,
+            name_to_value,
+        }
+    }
+
+    /// Looks up the token name for a given integer value, if it's known.
+    pub fn get_name(&self, value: u32) -> Option<&str> {
+        self.value_to_name.get(&value).map(Cow::as_ref)
+    }
+
+    /// Looks up the integer value for a given token name, if it's known.
+    pub fn get_value(&self, name: &str) -> Option<u32> {
+        self.name_to_value.get(name).copied()
+    }
+}
@@ -1,7 +1,10 @@
-use std::io::{Read, Write};
+use std::{
+    collections::HashSet,
+    io::{Read, Write},
+};
 
-use rbx_dom_weak::RbxValue;
-use rbx_reflection::RbxPropertyDescriptor;
+use rbx_dom_weak::{RbxId, RbxTree, RbxValue, RbxValueConversion, RbxValueType};
+use rbx_reflection::{RbxEnumDescriptor, RbxPropertyDescriptor, RbxPropertyTypeDescriptor};
 
 use crate::{
     deserializer_core::XmlEventReader,
@@ -31,6 +34,12 @@ pub fn find_canonical_property_descriptor(
         .map(|(canonical, _serialized)| canonical)
 }
 
+/// Finds the descriptor for the enum with the given name, such as
+/// `FormFactor` or `Genre`.
+pub fn find_enum_descriptor(enum_name: &str) -> Option<&'static RbxEnumDescriptor> {
+    rbx_reflection::get_enum_descriptor(enum_name)
+}
+
 pub fn find_serialized_property_descriptor(
     class_name: &str,
     property_name: &str,
@@ -103,4 +112,214 @@ fn find_property_descriptors(
             return None;
         }
     }
+}
+
+/// A property on an instance in an `RbxTree` that could not be migrated to
+/// its canonical name and type during `normalize_properties`.
+#[derive(Debug)]
+pub struct NormalizeError {
+    pub id: RbxId,
+    pub class_name: String,
+    pub property_name: String,
+    pub kind: NormalizeErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NormalizeErrorKind {
+    /// The property's value couldn't be converted to the canonical
+    /// property's type.
+    UnsupportedConversion,
+
+    /// The instance already had a value set under the canonical property
+    /// name, or another alias on the same instance is being migrated to it,
+    /// so migrating this alias would have silently overwritten it.
+    CanonicalAlreadyPresent,
+}
+
+/// Walks every instance in `tree`, rewriting deprecated property aliases
+/// (like `Part.Color`) to their canonical name and type (like
+/// `Part.Color3uint8`). This is useful for cleaning up legacy trees loaded
+/// from old XML files into a single canonical representation before
+/// re-serializing them.
+///
+/// Properties that can't be converted to their canonical type, or whose
+/// canonical name is already taken on the instance, are left untouched and
+/// reported back as errors. Properties whose canonical type isn't
+/// implemented are left untouched without an error. Every other property is
+/// still migrated.
+pub fn normalize_properties(tree: &mut RbxTree) -> Result<(), Vec<NormalizeError>> {
+    let mut ids = Vec::new();
+    collect_ids(tree, tree.get_root_id(), &mut ids);
+
+    let mut errors = Vec::new();
+    for id in ids {
+        normalize_instance_properties(tree, id, &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_ids(tree: &RbxTree, id: RbxId, ids: &mut Vec<RbxId>) {
+    ids.push(id);
+
+    let instance = tree.get_instance(id).unwrap();
+    for child_id in instance.get_children_ids() {
+        collect_ids(tree, *child_id, ids);
+    }
+}
+
+fn normalize_instance_properties(tree: &mut RbxTree, id: RbxId, errors: &mut Vec<NormalizeError>) {
+    let instance = tree.get_instance(id).unwrap();
+    let class_name = instance.class_name.clone();
+
+    let mut renames = Vec::new();
+
+    // Canonical names already claimed by a rename in this batch, so that two
+    // deprecated aliases of the same property can't clobber each other.
+    let mut claimed_canonical_names = HashSet::new();
+
+    for (property_name, value) in &instance.properties {
+        let canonical_descriptor = match find_canonical_property_descriptor(&class_name, property_name) {
+            Some(descriptor) => descriptor,
+            None => continue,
+        };
+
+        let canonical_name = canonical_descriptor.name();
+
+        let decision = decide_rename(
+            canonical_name,
+            property_name,
+            instance.properties.contains_key(canonical_name),
+            claimed_canonical_names.contains(canonical_name),
+        );
+
+        match decision {
+            RenameDecision::AlreadyCanonical => continue,
+            RenameDecision::Collision => {
+                // The instance already has a value under the canonical name,
+                // or another alias already claimed it; migrating this alias
+                // on top of it would silently discard whichever value we
+                // didn't keep, so leave this alias alone and report it
+                // instead.
+                errors.push(NormalizeError {
+                    id,
+                    class_name: class_name.clone(),
+                    property_name: property_name.clone(),
+                    kind: NormalizeErrorKind::CanonicalAlreadyPresent,
+                });
+                continue;
+            }
+            RenameDecision::Rename => {
+                claimed_canonical_names.insert(canonical_name.to_string());
+            }
+        }
+
+        let value_type = match canonical_descriptor.property_type() {
+            RbxPropertyTypeDescriptor::Data(value_type) => *value_type,
+            RbxPropertyTypeDescriptor::Enum(_) => RbxValueType::Enum,
+
+            // Not something we know how to convert to; leave it as-is
+            // rather than reporting an error consumers can't act on.
+            RbxPropertyTypeDescriptor::UnimplementedType(_) => continue,
+        };
+
+        match value.try_convert_ref(value_type) {
+            RbxValueConversion::Converted(converted) => {
+                renames.push((property_name.clone(), canonical_name.to_string(), Some(converted)));
+            }
+            RbxValueConversion::Unnecessary => {
+                renames.push((property_name.clone(), canonical_name.to_string(), None));
+            }
+            RbxValueConversion::Failed => {
+                errors.push(NormalizeError {
+                    id,
+                    class_name: class_name.clone(),
+                    property_name: property_name.clone(),
+                    kind: NormalizeErrorKind::UnsupportedConversion,
+                });
+            }
+        }
+    }
+
+    let instance = tree.get_instance_mut(id).unwrap();
+    for (old_name, new_name, converted) in renames {
+        let value = instance.properties.remove(&old_name).unwrap();
+        instance.properties.insert(new_name, converted.unwrap_or(value));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RenameDecision {
+    /// `property_name` is already the canonical name; nothing to do.
+    AlreadyCanonical,
+
+    /// Migrating `property_name` to the canonical name would silently
+    /// overwrite a value the instance already has there.
+    Collision,
+
+    /// `property_name` should be migrated to the canonical name.
+    Rename,
+}
+
+/// Decides what should happen to a single alias property found on an
+/// instance, in isolation from the rest of the tree walk so this logic is
+/// testable without an `RbxTree` or the reflection database.
+fn decide_rename(
+    canonical_name: &str,
+    property_name: &str,
+    already_has_canonical: bool,
+    already_claimed: bool,
+) -> RenameDecision {
+    if canonical_name == property_name {
+        RenameDecision::AlreadyCanonical
+    } else if already_has_canonical || already_claimed {
+        RenameDecision::Collision
+    } else {
+        RenameDecision::Rename
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decide_rename_already_canonical() {
+        assert_eq!(
+            decide_rename("Color3uint8", "Color3uint8", false, false),
+            RenameDecision::AlreadyCanonical,
+        );
+    }
+
+    #[test]
+    fn decide_rename_simple_migration() {
+        assert_eq!(
+            decide_rename("Color3uint8", "Color", false, false),
+            RenameDecision::Rename,
+        );
+    }
+
+    #[test]
+    fn decide_rename_collides_with_existing_canonical_value() {
+        // If the instance already has both `Color` and `Color3uint8` set,
+        // migrating `Color` onto `Color3uint8` must not silently clobber it.
+        assert_eq!(
+            decide_rename("Color3uint8", "Color", true, false),
+            RenameDecision::Collision,
+        );
+    }
+
+    #[test]
+    fn decide_rename_collides_with_another_alias_in_the_same_batch() {
+        // If two different aliases both map to the same canonical property,
+        // only the first one claims it; the second is a collision too.
+        assert_eq!(
+            decide_rename("Color3uint8", "BrickColor", false, true),
+            RenameDecision::Collision,
+        );
+    }
 }
\ No newline at end of file
@@ -4,7 +4,7 @@ use std::{
     io::Write,
 };
 
-use rbx_reflection::RbxPropertyTypeDescriptor;
+use rbx_reflection::{RbxPropertyTypeDescriptor, Scriptability};
 use rbx_dom_weak::{
     RbxTree,
     RbxValue,
@@ -15,7 +15,7 @@ use rbx_dom_weak::{
 };
 
 use crate::{
-    core::find_serialized_property_descriptor,
+    core::{find_enum_descriptor, find_serialized_property_descriptor},
     types::write_value_xml,
     error::{EncodeError as NewEncodeError, EncodeErrorKind},
 };
@@ -76,6 +76,9 @@ pub enum EncodePropertyBehavior {
 #[derive(Debug, Clone)]
 pub struct EncodeOptions {
     property_behavior: EncodePropertyBehavior,
+    human_readable_enums: bool,
+    respect_can_save: bool,
+    respect_scriptability: bool,
 }
 
 impl EncodeOptions {
@@ -84,6 +87,9 @@ impl EncodeOptions {
     pub fn new() -> Self {
         EncodeOptions {
             property_behavior: EncodePropertyBehavior::IgnoreUnknown,
+            human_readable_enums: false,
+            respect_can_save: false,
+            respect_scriptability: false,
         }
     }
 
@@ -97,9 +103,63 @@ impl EncodeOptions {
         }
     }
 
+    /// Determines whether enum-typed properties are annotated with their
+    /// token name (e.g. `Enum.FormFactor.Symmetric`'s `Symmetric`) as a
+    /// `label` attribute alongside the raw integer value Roblox Studio
+    /// itself writes.
+    ///
+    /// The integer value is always what gets read back by `rbx_xml` (and by
+    /// Studio, which ignores unrecognized attributes), so turning this on
+    /// never changes how a file round-trips. Enum values that aren't
+    /// recognized by rbx_xml's reflection database are written without a
+    /// label, except under `EncodePropertyBehavior::ErrorOnUnknown`, where
+    /// they cause an error instead.
+    #[inline]
+    pub fn human_readable_enums(self, human_readable_enums: bool) -> Self {
+        EncodeOptions {
+            human_readable_enums,
+            ..self
+        }
+    }
+
+    /// Determines whether rbx_xml should skip properties that the API dump
+    /// marks as `CanSave = false`, matching the set of properties Studio
+    /// itself writes out when saving a place or model file.
+    #[inline]
+    pub fn respect_can_save(self, respect_can_save: bool) -> Self {
+        EncodeOptions {
+            respect_can_save,
+            ..self
+        }
+    }
+
+    /// Determines whether rbx_xml should skip properties the API dump marks
+    /// as `NotScriptable`, i.e. those with a `Scriptability` of `None`. These
+    /// are properties Lua scripts can't see, so most consumers reading a file
+    /// back don't expect them to be present either.
+    #[inline]
+    pub fn respect_scriptability(self, respect_scriptability: bool) -> Self {
+        EncodeOptions {
+            respect_scriptability,
+            ..self
+        }
+    }
+
     pub(crate) fn use_reflection(&self) -> bool {
         self.property_behavior != EncodePropertyBehavior::NoReflection
     }
+
+    pub(crate) fn write_enum_names(&self) -> bool {
+        self.human_readable_enums
+    }
+
+    pub(crate) fn respects_can_save(&self) -> bool {
+        self.respect_can_save
+    }
+
+    pub(crate) fn respects_scriptability(&self) -> bool {
+        self.respect_scriptability
+    }
 }
 
 impl Default for EncodeOptions {
@@ -187,9 +247,22 @@ fn serialize_instance<'a, W: Write>(
         };
 
         if let Some(serialized_descriptor) = maybe_serialized_descriptor {
+            if state.options.respects_can_save() && !serialized_descriptor.can_save() {
+                continue;
+            }
+
+            if state.options.respects_scriptability() && serialized_descriptor.scriptability() == Scriptability::None {
+                continue;
+            }
+
+            let mut enum_name = None;
+
             let value_type = match serialized_descriptor.property_type() {
                 RbxPropertyTypeDescriptor::Data(value_type) => *value_type,
-                RbxPropertyTypeDescriptor::Enum(_enum_name) => RbxValueType::Enum,
+                RbxPropertyTypeDescriptor::Enum(name) => {
+                    enum_name = Some(name);
+                    RbxValueType::Enum
+                }
                 RbxPropertyTypeDescriptor::UnimplementedType(_) => {
                     // Properties with types that aren't implemented yet are
                     // effectively unknown properties, so we handle them
@@ -227,6 +300,23 @@ fn serialize_instance<'a, W: Write>(
                 })),
             };
 
+            if let (Some(enum_name), RbxValue::Enum { value: raw_value }) = (enum_name, converted_value.as_ref()) {
+                if state.options.write_enum_names() {
+                    let token_name = find_enum_descriptor(enum_name)
+                        .and_then(|descriptor| descriptor.get_name(*raw_value));
+
+                    if token_name.is_none() && state.options.property_behavior == EncodePropertyBehavior::ErrorOnUnknown {
+                        return Err(writer.error(EncodeErrorKind::UnknownProperty {
+                            class_name: instance.class_name.clone(),
+                            property_name: property_name.clone(),
+                        }));
+                    }
+
+                    write_token_xml(writer, &serialized_descriptor.name(), *raw_value, token_name)?;
+                    continue;
+                }
+            }
+
             write_value_xml(writer, state, &serialized_descriptor.name(), &converted_value)?;
         } else {
             match state.options.property_behavior {
@@ -259,6 +349,31 @@ fn serialize_instance<'a, W: Write>(
     Ok(())
 }
 
+/// Writes an enum-typed property as a `<token>` element, the same
+/// representation the reflection-driven path in `serialize_instance` would
+/// otherwise produce. The value is always written as the raw integer so that
+/// any `rbx_xml` reader -- including one built before `human_readable_enums`
+/// existed -- reads the property back unchanged; `token_name`, when present,
+/// is added purely as a `label` attribute for humans skimming the file.
+fn write_token_xml<W: Write>(
+    writer: &mut XmlEventWriter<W>,
+    property_name: &str,
+    raw_value: u32,
+    token_name: Option<&str>,
+) -> Result<(), NewEncodeError> {
+    let mut start_event = XmlWriteEvent::start_element("token").attr("name", property_name);
+
+    if let Some(token_name) = token_name {
+        start_event = start_event.attr("label", token_name);
+    }
+
+    writer.write(start_event)?;
+    writer.write_string(&raw_value.to_string())?;
+    writer.write(XmlWriteEvent::end_element())?;
+
+    Ok(())
+}
+
 fn serialize_shared_strings<W: Write>(
     writer: &mut XmlEventWriter<W>,
     state: &mut EmitState,